@@ -0,0 +1,133 @@
+// Copyright 2024 Shun Takebayashi
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An opt-in, on-disk cache for previously computed hashes, enabled via the
+//! `cache` feature. Entries are keyed by the SHA-1 digest of the source
+//! image bytes plus the name of the algorithm that produced the hash, so
+//! repeatedly hashing a large, mostly-unchanged image library skips the
+//! resize+transform work for files that have already been hashed.
+
+use sha1::{Digest, Sha1};
+use std::path::{Path, PathBuf};
+
+use crate::{bytes_to_hex, ImageHash};
+
+/// An on-disk cache of previously computed [`ImageHash`] values.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// Opens (creating if necessary) a cache rooted at `path`.
+    pub fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let dir = path.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Cache { dir })
+    }
+
+    /// Computes the hex-encoded SHA-1 digest of `bytes`, used as the cache
+    /// key for the image they came from.
+    pub fn digest(bytes: &[u8]) -> String {
+        let mut hasher = Sha1::new();
+        hasher.update(bytes);
+        bytes_to_hex(&hasher.finalize())
+    }
+
+    /// Looks up a previously cached hash for `digest` computed by `algo`.
+    ///
+    /// `algo` must uniquely identify not just the hashing algorithm but also
+    /// the `ImageOp` it was run with (e.g. `"ahash-8x8-Lanczos3"` rather than
+    /// just `"ahash"`); entries keyed on the algorithm name alone collide across
+    /// different hash sizes and silently return the wrong hash. The
+    /// hasher-provided `hash_cached` methods already do this.
+    pub fn get(&self, digest: &str, algo: &str) -> Option<ImageHash> {
+        let hex = std::fs::read_to_string(self.entry_path(digest, algo)).ok()?;
+        ImageHash::from_hex(hex.trim()).ok()
+    }
+
+    /// Stores `hash` for `digest` computed by `algo`. See [`Cache::get`] for
+    /// the requirements on `algo`.
+    pub fn put(&self, digest: &str, algo: &str, hash: &ImageHash) -> std::io::Result<()> {
+        std::fs::write(self.entry_path(digest, algo), hash.to_hex())
+    }
+
+    pub(crate) fn hash_cached(
+        &self,
+        bytes: &[u8],
+        algo: &str,
+        compute: impl FnOnce() -> ImageHash,
+    ) -> ImageHash {
+        let digest = Cache::digest(bytes);
+        if let Some(hash) = self.get(&digest, algo) {
+            return hash;
+        }
+        let hash = compute();
+        let _ = self.put(&digest, algo, &hash);
+        hash
+    }
+
+    fn entry_path(&self, digest: &str, algo: &str) -> PathBuf {
+        self.dir.join(format!("{}-{}.hash", digest, algo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn temp_cache() -> Cache {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!("imagehash-cache-test-{}-{}", std::process::id(), n));
+        Cache::new(dir).unwrap()
+    }
+
+    #[test]
+    fn get_put_round_trips_a_hash() {
+        let cache = temp_cache();
+        let hash = ImageHash::from_bits(&[true, false, true, true, false, false, true, false]);
+        cache.put("digest", "ahash-8x8", &hash).unwrap();
+        assert_eq!(cache.get("digest", "ahash-8x8"), Some(hash));
+    }
+
+    #[test]
+    fn get_misses_for_an_unknown_digest() {
+        let cache = temp_cache();
+        assert_eq!(cache.get("nonexistent", "ahash-8x8"), None);
+    }
+
+    #[test]
+    fn hash_cached_short_circuits_compute_on_a_hit() {
+        let cache = temp_cache();
+        let hash = ImageHash::from_bits(&[true; 64]);
+        let calls = Cell::new(0);
+
+        let first = cache.hash_cached(b"some bytes", "ahash-8x8", || {
+            calls.set(calls.get() + 1);
+            hash.clone()
+        });
+        // A different closure that would produce a different hash, to prove
+        // it's never actually called on the second, cached lookup.
+        let second = cache.hash_cached(b"some bytes", "ahash-8x8", || {
+            calls.set(calls.get() + 1);
+            ImageHash::from_bits(&[false; 64])
+        });
+
+        assert_eq!(first, hash);
+        assert_eq!(second, hash);
+        assert_eq!(calls.get(), 1);
+    }
+}
@@ -18,6 +18,8 @@
 //!
 //! - Average Hash (aHash)
 //! - Difference Hash (dHash)
+//! - Perceptual Hash (pHash)
+//! - Median Hash (mHash)
 //!
 //! ## Usage
 //!
@@ -30,18 +32,188 @@
 //!
 //! let hasher = AverageHash::default();
 //! let hash = hasher.hash(&img);
-//! println!("{}", hash); // hex-encoded hash string
+//! println!("{}", hash.to_hex());
 //! ```
 
 pub use image::imageops::FilterType;
 
+use base64::Engine as _;
+
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "cache")]
+pub use cache::Cache;
+
 /// Contains the image pre-processing parameters.
 pub struct ImageOp {
-    pub width: u8,
-    pub height: u8,
+    pub width: u32,
+    pub height: u32,
     pub filter: FilterType,
 }
 
+/// A computed image hash.
+///
+/// `ImageHash` wraps the raw bit buffer produced by a hasher so that two
+/// hashes can be compared with [`ImageHash::dist`] without the caller having
+/// to parse hex strings themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageHash {
+    bits: usize,
+    bytes: Vec<u8>,
+}
+
+/// Selects the text encoding used by [`ImageHash::encode`]/[`ImageHash::decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Lowercase hexadecimal, two characters per byte.
+    Hex,
+    /// Standard base64 (RFC 4648), about 33% more compact than hex.
+    Base64,
+}
+
+/// An error returned when decoding an [`ImageHash`] from text fails.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The input was not valid hex.
+    InvalidHex(std::num::ParseIntError),
+    /// The input was not valid base64.
+    InvalidBase64(base64::DecodeError),
+    /// The decoded payload was too short to contain a bit length.
+    Truncated,
+    /// The hex input had an odd length or contained non-ASCII bytes.
+    MalformedHex,
+    /// The decoded byte buffer's length didn't match its declared bit count.
+    LengthMismatch,
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::InvalidHex(e) => write!(f, "invalid hex: {}", e),
+            DecodeError::InvalidBase64(e) => write!(f, "invalid base64: {}", e),
+            DecodeError::Truncated => write!(f, "encoded hash is truncated"),
+            DecodeError::MalformedHex => {
+                write!(f, "hex input must have an even length and be ASCII")
+            }
+            DecodeError::LengthMismatch => {
+                write!(f, "decoded byte length doesn't match the declared bit count")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl ImageHash {
+    fn from_bits(bits: &[bool]) -> Self {
+        ImageHash {
+            bits: bits.len(),
+            bytes: bits_to_bytes(bits),
+        }
+    }
+
+    /// Computes the Hamming distance to `other`, i.e. the number of bits
+    /// that differ between the two hashes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` and `other` were produced by hashers with different
+    /// bit lengths (e.g. comparing a 64-bit aHash against a 256-bit mHash)
+    /// — such a comparison is meaningless and would otherwise silently
+    /// compare only the shorter hash's bits.
+    pub fn dist(&self, other: &ImageHash) -> u32 {
+        assert_eq!(
+            self.bits, other.bits,
+            "cannot compare hashes of different bit lengths ({} vs {})",
+            self.bits, other.bits
+        );
+        self.bytes
+            .iter()
+            .zip(other.bytes.iter())
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+
+    /// Computes the Hamming distance to `other` as a fraction of the total
+    /// number of bits, i.e. `dist() / bits`.
+    pub fn dist_ratio(&self, other: &ImageHash) -> f64 {
+        self.dist(other) as f64 / self.bits as f64
+    }
+
+    /// Serializes the bit length and raw bytes into a single payload so that
+    /// `from_hex`/`from_base64` can reconstruct the exact bit buffer, not
+    /// just a round number of bytes.
+    fn to_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(4 + self.bytes.len());
+        payload.extend_from_slice(&(self.bits as u32).to_le_bytes());
+        payload.extend_from_slice(&self.bytes);
+        payload
+    }
+
+    fn from_payload(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < 4 {
+            return Err(DecodeError::Truncated);
+        }
+        let bits = u32::from_le_bytes(payload[..4].try_into().unwrap()) as usize;
+        let bytes = &payload[4..];
+        if bytes.len() != bits.div_ceil(8) {
+            return Err(DecodeError::LengthMismatch);
+        }
+        Ok(ImageHash {
+            bits,
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Encodes the hash as a lowercase hex string.
+    pub fn to_hex(&self) -> String {
+        bytes_to_hex(&self.to_payload())
+    }
+
+    /// Reconstructs a hash previously encoded with [`ImageHash::to_hex`].
+    pub fn from_hex(hex: &str) -> Result<Self, DecodeError> {
+        if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+            return Err(DecodeError::MalformedHex);
+        }
+        let payload = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(DecodeError::InvalidHex)?;
+        ImageHash::from_payload(&payload)
+    }
+
+    /// Encodes the hash as a base64 string, about 33% more compact than hex.
+    pub fn to_base64(&self) -> String {
+        base64::engine::general_purpose::STANDARD.encode(self.to_payload())
+    }
+
+    /// Reconstructs a hash previously encoded with [`ImageHash::to_base64`].
+    pub fn from_base64(b64: &str) -> Result<Self, DecodeError> {
+        let payload = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .map_err(DecodeError::InvalidBase64)?;
+        ImageHash::from_payload(&payload)
+    }
+
+    /// Encodes the hash using the given [`Encoding`].
+    pub fn encode(&self, encoding: Encoding) -> String {
+        match encoding {
+            Encoding::Hex => self.to_hex(),
+            Encoding::Base64 => self.to_base64(),
+        }
+    }
+
+    /// Reconstructs a hash encoded with [`ImageHash::encode`] using the given
+    /// [`Encoding`].
+    pub fn decode(s: &str, encoding: Encoding) -> Result<Self, DecodeError> {
+        match encoding {
+            Encoding::Hex => ImageHash::from_hex(s),
+            Encoding::Base64 => ImageHash::from_base64(s),
+        }
+    }
+}
+
 /// Provides average hash (aHash) calculation.
 pub struct AverageHash<'a> {
     op: &'a ImageOp,
@@ -58,11 +230,17 @@ impl<'a> AverageHash<'a> {
         AverageHash { op }
     }
 
-    /// Calculates average hash (aHash) of the image and returns as a hex string.
-    pub fn hash(&self, image: &image::DynamicImage) -> String {
+    /// Calculates average hash (aHash) of the image.
+    pub fn hash(&self, image: &image::DynamicImage) -> ImageHash {
         let bits = average_hash(image, self.op);
-        let bytes = bits_to_bytes(&bits);
-        bytes_to_hex(&bytes)
+        ImageHash::from_bits(&bits)
+    }
+
+    /// Calculates average hash (aHash) of `image`, reusing a previous result for `bytes` from `cache` if present.
+    #[cfg(feature = "cache")]
+    pub fn hash_cached(&self, image: &image::DynamicImage, bytes: &[u8], cache: &Cache) -> ImageHash {
+        let algo = format!("ahash-{}x{}-{:?}", self.op.width, self.op.height, self.op.filter);
+        cache.hash_cached(bytes, &algo, || self.hash(image))
     }
 }
 
@@ -83,10 +261,91 @@ impl Default for AverageHash<'_> {
 pub fn average_hash(image: &image::DynamicImage, op: &ImageOp) -> Vec<bool> {
     let preprocessed = image
         .grayscale()
-        .resize_exact(op.width as u32, op.height as u32, op.filter);
+        .resize_exact(op.width, op.height, op.filter);
+    let pixels = preprocessed.into_luma8().into_raw();
+    threshold_bits(&pixels, ThresholdStrategy::Mean)
+}
+
+/// Provides median hash (mHash) calculation.
+pub struct MedianHash<'a> {
+    op: &'a ImageOp,
+}
+
+impl<'a> MedianHash<'a> {
+    /// Creates a new `MedianHasher` with default parameters.
+    pub fn new() -> Self {
+        MedianHash::default()
+    }
+
+    /// Creates a new `MedianHasher` with the specified parameters.
+    pub fn with_op(op: &'a ImageOp) -> Self {
+        MedianHash { op }
+    }
+
+    /// Calculates median hash (mHash) of the image.
+    pub fn hash(&self, image: &image::DynamicImage) -> ImageHash {
+        let bits = median_hash(image, self.op);
+        ImageHash::from_bits(&bits)
+    }
+
+    /// Calculates median hash (mHash) of `image`, reusing a previous result for `bytes` from `cache` if present.
+    #[cfg(feature = "cache")]
+    pub fn hash_cached(&self, image: &image::DynamicImage, bytes: &[u8], cache: &Cache) -> ImageHash {
+        let algo = format!("mhash-{}x{}-{:?}", self.op.width, self.op.height, self.op.filter);
+        cache.hash_cached(bytes, &algo, || self.hash(image))
+    }
+}
+
+impl Default for MedianHash<'_> {
+    /// Creates a new `MedianHasher` with default parameters.
+    fn default() -> Self {
+        MedianHash {
+            op: &ImageOp {
+                width: 8,
+                height: 8,
+                filter: FilterType::Lanczos3,
+            },
+        }
+    }
+}
+
+/// Calculates median hash (mHash) of the image.
+///
+/// Unlike [`average_hash`], each pixel is thresholded against the median of
+/// the resized luma pixels rather than their mean, which is noticeably more
+/// stable for images with large flat regions plus small bright outliers.
+pub fn median_hash(image: &image::DynamicImage, op: &ImageOp) -> Vec<bool> {
+    let preprocessed = image
+        .grayscale()
+        .resize_exact(op.width, op.height, op.filter);
     let pixels = preprocessed.into_luma8().into_raw();
-    let average = pixels.iter().map(|i| u16::from(*i)).sum::<u16>() / (op.width * op.height) as u16;
-    pixels.iter().map(|&v| v as u16 > average).collect()
+    threshold_bits(&pixels, ThresholdStrategy::Median)
+}
+
+/// Strategy used by [`threshold_bits`] to decide the value each pixel is
+/// compared against when turning a resized grayscale image into hash bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdStrategy {
+    /// Threshold against the arithmetic mean of the pixels.
+    Mean,
+    /// Threshold against the median of the pixels.
+    Median,
+}
+
+/// Thresholds `pixels` into hash bits according to `strategy`, setting a bit
+/// when its pixel exceeds the chosen threshold value.
+fn threshold_bits(pixels: &[u8], strategy: ThresholdStrategy) -> Vec<bool> {
+    let threshold = match strategy {
+        ThresholdStrategy::Mean => {
+            pixels.iter().map(|&v| u32::from(v)).sum::<u32>() / pixels.len() as u32
+        }
+        ThresholdStrategy::Median => {
+            let mut sorted = pixels.to_vec();
+            sorted.sort_unstable();
+            u32::from(sorted[sorted.len() / 2])
+        }
+    };
+    pixels.iter().map(|&v| u32::from(v) > threshold).collect()
 }
 
 /// Provides difference hash (dHash) calculation.
@@ -105,11 +364,17 @@ impl<'a> DifferenceHash<'a> {
         DifferenceHash { op }
     }
 
-    /// Calculates difference hash (dHash) of the image and returns as a hex string.
-    pub fn hash(&self, image: &image::DynamicImage) -> String {
+    /// Calculates difference hash (dHash) of the image.
+    pub fn hash(&self, image: &image::DynamicImage) -> ImageHash {
         let bits = difference_hash(image, self.op);
-        let bytes = bits_to_bytes(&bits);
-        bytes_to_hex(&bytes)
+        ImageHash::from_bits(&bits)
+    }
+
+    /// Calculates difference hash (dHash) of `image`, reusing a previous result for `bytes` from `cache` if present.
+    #[cfg(feature = "cache")]
+    pub fn hash_cached(&self, image: &image::DynamicImage, bytes: &[u8], cache: &Cache) -> ImageHash {
+        let algo = format!("dhash-{}x{}-{:?}", self.op.width, self.op.height, self.op.filter);
+        cache.hash_cached(bytes, &algo, || self.hash(image))
     }
 }
 
@@ -127,10 +392,20 @@ impl Default for DifferenceHash<'_> {
 }
 
 /// Calculates difference hash (dHash) of the image.
+///
+/// # Panics
+///
+/// Panics if `op.width < 2`, since dHash compares each pixel to its
+/// neighbour and needs at least two columns to do so.
 pub fn difference_hash(image: &image::DynamicImage, op: &ImageOp) -> Vec<bool> {
+    assert!(
+        op.width >= 2,
+        "difference_hash requires op.width >= 2, got {}",
+        op.width
+    );
     let preprocessed = image
         .grayscale()
-        .resize_exact(op.width as u32, op.height as u32, op.filter);
+        .resize_exact(op.width, op.height, op.filter);
     let pixels = preprocessed.into_luma8().into_raw();
     let mut bits = vec![false; ((op.width - 1) * op.height) as usize];
     for y in 0..op.height {
@@ -143,6 +418,163 @@ pub fn difference_hash(image: &image::DynamicImage, op: &ImageOp) -> Vec<bool> {
     bits
 }
 
+/// Precomputed cosine tables for a separable 2D DCT-II of a fixed size.
+///
+/// Building these tables is the expensive part of perceptual hashing, so
+/// `PerceptualHash` keeps one around and reuses it across calls to `hash`
+/// instead of recomputing it for every image.
+pub struct DctMatrix {
+    size: usize,
+    table: Vec<f64>,
+}
+
+impl DctMatrix {
+    /// Precomputes the cosine table for a `size`x`size` DCT-II.
+    pub fn new(size: usize) -> Self {
+        let mut table = vec![0.0; size * size];
+        for k in 0..size {
+            for n in 0..size {
+                table[k * size + n] =
+                    (std::f64::consts::PI / size as f64 * (n as f64 + 0.5) * k as f64).cos();
+            }
+        }
+        DctMatrix { size, table }
+    }
+
+    fn transform_1d(&self, input: &[f64], output: &mut [f64]) {
+        for (k, out) in output.iter_mut().enumerate() {
+            *out = (0..self.size)
+                .map(|n| input[n] * self.table[k * self.size + n])
+                .sum();
+        }
+    }
+
+    /// Applies a separable 2D DCT-II to a flattened, row-major `size`x`size` matrix.
+    fn transform_2d(&self, matrix: &[f64]) -> Vec<f64> {
+        let size = self.size;
+        let mut rows = vec![0.0; size * size];
+        for y in 0..size {
+            self.transform_1d(&matrix[y * size..(y + 1) * size], &mut rows[y * size..(y + 1) * size]);
+        }
+        let mut column = vec![0.0; size];
+        let mut transformed_column = vec![0.0; size];
+        let mut result = vec![0.0; size * size];
+        for x in 0..size {
+            for y in 0..size {
+                column[y] = rows[y * size + x];
+            }
+            self.transform_1d(&column, &mut transformed_column);
+            for y in 0..size {
+                result[y * size + x] = transformed_column[y];
+            }
+        }
+        result
+    }
+}
+
+/// Provides perceptual hash (pHash) calculation.
+pub struct PerceptualHash<'a> {
+    op: &'a ImageOp,
+    dct: DctMatrix,
+}
+
+impl<'a> PerceptualHash<'a> {
+    /// Creates a new `PerceptualHash` with default parameters.
+    pub fn new() -> Self {
+        PerceptualHash::default()
+    }
+
+    /// Creates a new `PerceptualHash` with the specified parameters.
+    ///
+    /// Panics if `op.width != op.height` or `op.width < 8`.
+    pub fn with_op(op: &'a ImageOp) -> Self {
+        assert_eq!(
+            op.width, op.height,
+            "PerceptualHash requires op.width == op.height, got {}x{}",
+            op.width, op.height
+        );
+        assert!(
+            op.width >= 8,
+            "PerceptualHash requires op.width >= 8, got {}",
+            op.width
+        );
+        PerceptualHash {
+            op,
+            dct: DctMatrix::new(op.width as usize),
+        }
+    }
+
+    /// Calculates perceptual hash (pHash) of the image.
+    pub fn hash(&self, image: &image::DynamicImage) -> ImageHash {
+        let bits = perceptual_hash(image, self.op, &self.dct);
+        ImageHash::from_bits(&bits)
+    }
+
+    /// Calculates perceptual hash (pHash) of `image`, reusing a previous result for `bytes` from `cache` if present.
+    #[cfg(feature = "cache")]
+    pub fn hash_cached(&self, image: &image::DynamicImage, bytes: &[u8], cache: &Cache) -> ImageHash {
+        let algo = format!("phash-{}x{}-{:?}", self.op.width, self.op.height, self.op.filter);
+        cache.hash_cached(bytes, &algo, || self.hash(image))
+    }
+}
+
+impl Default for PerceptualHash<'_> {
+    /// Creates a new `PerceptualHash` with default parameters.
+    fn default() -> Self {
+        let op = &ImageOp {
+            width: 32,
+            height: 32,
+            filter: FilterType::Lanczos3,
+        };
+        PerceptualHash {
+            op,
+            dct: DctMatrix::new(op.width as usize),
+        }
+    }
+}
+
+/// Calculates perceptual hash (pHash) of the image.
+///
+/// The image is resized to `op.width`x`op.height` (32x32 by convention), a
+/// separable 2D DCT-II is applied, and the hash bit for each of the top-left
+/// 8x8 low-frequency coefficients (excluding the DC term) is set when that
+/// coefficient exceeds the median of the other 63 coefficients.
+///
+/// Panics if `op.width != op.height` or `op.width < 8`.
+pub fn perceptual_hash(image: &image::DynamicImage, op: &ImageOp, dct: &DctMatrix) -> Vec<bool> {
+    assert_eq!(
+        op.width, op.height,
+        "perceptual_hash requires op.width == op.height, got {}x{}",
+        op.width, op.height
+    );
+    assert!(
+        op.width >= 8,
+        "perceptual_hash requires op.width >= 8, got {}",
+        op.width
+    );
+    let preprocessed = image
+        .grayscale()
+        .resize_exact(op.width, op.height, op.filter);
+    let pixels = preprocessed.into_luma8().into_raw();
+    let size = op.width as usize;
+    let matrix: Vec<f64> = pixels.iter().map(|&p| p as f64).collect();
+    let coeffs = dct.transform_2d(&matrix);
+
+    let low_freq: Vec<f64> = (0..8)
+        .flat_map(|y| (0..8).map(move |x| (x, y)))
+        .filter(|&(x, y)| (x, y) != (0, 0))
+        .map(|(x, y)| coeffs[y * size + x])
+        .collect();
+    let mut sorted = low_freq.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median = sorted[sorted.len() / 2];
+
+    (0..8)
+        .flat_map(|y| (0..8).map(move |x| (x, y)))
+        .map(|(x, y)| (x, y) != (0, 0) && coeffs[y * size + x] > median)
+        .collect()
+}
+
 fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
     let mut bytes = vec![0; (bits.len() + 7) / 8];
     for (i, bit) in bits.iter().enumerate() {
@@ -153,10 +585,121 @@ fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
     bytes
 }
 
-fn bytes_to_hex(bytes: &[u8]) -> String {
+pub(crate) fn bytes_to_hex(bytes: &[u8]) -> String {
     let mut result = String::with_capacity(bytes.len() * 2);
     for &byte in bytes {
         result.push_str(&format!("{:02x}", byte));
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "different bit lengths")]
+    fn dist_rejects_mismatched_hash_lengths() {
+        let short = ImageHash::from_bits(&[true; 64]);
+        let long = ImageHash::from_bits(&[true; 256]);
+        short.dist(&long);
+    }
+
+    #[test]
+    fn hashes_above_64_bits_round_trip_and_compare_correctly() {
+        // 256 bits exercises a resolution well beyond a single u64, e.g. the
+        // 16x16 `ImageOp` a caller would use for a higher-fidelity aHash or mHash.
+        let mut bits = vec![true; 256];
+        bits[255] = false;
+        let hash = ImageHash::from_bits(&bits);
+        assert_eq!(hash.bits, 256);
+        assert_eq!(hash.to_payload().len(), 4 + 32);
+
+        let decoded = ImageHash::from_hex(&hash.to_hex()).unwrap();
+        assert_eq!(hash, decoded);
+        assert_eq!(hash.dist(&decoded), 0);
+
+        let all_true = ImageHash::from_bits(&vec![true; 256]);
+        assert_eq!(hash.dist(&all_true), 1);
+    }
+
+    #[test]
+    fn mean_and_median_thresholds_disagree_on_a_skewed_distribution() {
+        // The mean (470 / 8 = 58) sits just below the mid-range pixels, so
+        // they are set under Mean, while the median is 60 itself and only the
+        // bright outlier exceeds it — so the two strategies produce different bits.
+        let pixels = [10, 10, 10, 10, 60, 60, 60, 250];
+        let mean_bits = threshold_bits(&pixels, ThresholdStrategy::Mean);
+        let median_bits = threshold_bits(&pixels, ThresholdStrategy::Median);
+        assert_ne!(mean_bits, median_bits);
+        assert_eq!(mean_bits, vec![false, false, false, false, true, true, true, true]);
+        assert_eq!(median_bits, vec![false, false, false, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn base64_round_trip_preserves_bits() {
+        let hash = ImageHash::from_bits(&[true, false, true, true, false, false, true, false]);
+        let decoded = ImageHash::from_base64(&hash.to_base64()).unwrap();
+        assert_eq!(hash, decoded);
+        assert_eq!(hash.dist(&decoded), 0);
+    }
+
+    #[test]
+    fn hex_round_trip_preserves_bits() {
+        let hash = ImageHash::from_bits(&[true, false, true, true, false, false, true, false]);
+        let decoded = ImageHash::from_hex(&hash.to_hex()).unwrap();
+        assert_eq!(hash, decoded);
+        assert_eq!(hash.dist(&decoded), 0);
+    }
+
+    #[test]
+    fn from_hex_rejects_odd_length_instead_of_panicking() {
+        assert!(matches!(ImageHash::from_hex("abc"), Err(DecodeError::MalformedHex)));
+    }
+
+    #[test]
+    fn from_hex_rejects_payload_whose_length_disagrees_with_its_declared_bits() {
+        // 256 declared bits (little-endian u32) but only one byte of payload.
+        let hex = format!("{}{}", bytes_to_hex(&256u32.to_le_bytes()), "ff");
+        assert!(matches!(
+            ImageHash::from_hex(&hex),
+            Err(DecodeError::LengthMismatch)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "op.width == op.height")]
+    fn perceptual_hash_with_op_rejects_non_square_dimensions() {
+        let op = ImageOp {
+            width: 16,
+            height: 8,
+            filter: FilterType::Lanczos3,
+        };
+        PerceptualHash::with_op(&op);
+    }
+
+    #[test]
+    #[should_panic(expected = "op.width >= 8")]
+    fn perceptual_hash_with_op_rejects_dimensions_smaller_than_8x8() {
+        let op = ImageOp {
+            width: 4,
+            height: 4,
+            filter: FilterType::Lanczos3,
+        };
+        PerceptualHash::with_op(&op);
+    }
+
+    #[test]
+    fn dct_of_a_constant_signal_is_all_dc() {
+        // A flat input has no spatial frequency content, so a DCT-II of it
+        // should collapse to the DC term (index 0) with every other
+        // coefficient at zero.
+        let dct = DctMatrix::new(8);
+        let matrix = vec![1.0; 64];
+        let result = dct.transform_2d(&matrix);
+        assert!((result[0] - 64.0).abs() < 1e-9);
+        for &coeff in &result[1..] {
+            assert!(coeff.abs() < 1e-9, "expected ~0, got {coeff}");
+        }
+    }
+}